@@ -18,4 +18,9 @@ pub enum Error {
     InvalidStatus = 12,
     DuplicateAsset = 13,
     TooManyPayments = 14,
+    VolumeCapExceeded = 15,
+    InvalidConfig = 16,
+    NotEnoughAssets = 17,
+    AssetNotAllowed = 18,
+    BelowMinimum = 19,
 }
@@ -1,5 +1,5 @@
 use bridgelet_shared::Payment;
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -36,6 +36,31 @@ pub struct AccountExpired {
     pub amount_returned: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchPaymentReceived {
+    pub count: u32,
+    pub totals: Map<Address, i128>,
+}
+
+/// Payment received with the amount replaced by a payer-supplied commitment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommittedPaymentReceived {
+    pub asset: Address,
+    pub commitment: BytesN<32>,
+}
+
+/// SEP-31 style settlement notification, one per swept asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementNotification {
+    pub amount: i128,
+    pub asset: Address,
+    pub destination: Address,
+    pub settlement_ref: BytesN<32>,
+}
+
 pub fn emit_account_created(env: &Env, creator: Address, expiry_ledger: u32) {
     let event = AccountCreated {
         creator,
@@ -62,6 +87,16 @@ pub fn emit_multi_payment_received(env: &Env, asset: Address, amount: i128) {
     env.events().publish((symbol_short!("multi_pay"),), event);
 }
 
+pub fn emit_batch_payment_received(env: &Env, count: u32, totals: Map<Address, i128>) {
+    let event = BatchPaymentReceived { count, totals };
+    env.events().publish((symbol_short!("batch_pay"),), event);
+}
+
+pub fn emit_committed_payment_received(env: &Env, asset: Address, commitment: BytesN<32>) {
+    let event = CommittedPaymentReceived { asset, commitment };
+    env.events().publish((symbol_short!("pay_cmt"),), event);
+}
+
 pub fn emit_account_expired(env: &Env, recovery_address: Address, amount_returned: i128) {
     let event = AccountExpired {
         recovery_address,
@@ -69,3 +104,19 @@ pub fn emit_account_expired(env: &Env, recovery_address: Address, amount_returne
     };
     env.events().publish((symbol_short!("expired"),), event);
 }
+
+pub fn emit_settlement_notification(
+    env: &Env,
+    amount: i128,
+    asset: Address,
+    destination: Address,
+    settlement_ref: BytesN<32>,
+) {
+    let event = SettlementNotification {
+        amount,
+        asset,
+        destination,
+        settlement_ref,
+    };
+    env.events().publish((symbol_short!("settled"),), event);
+}
@@ -6,15 +6,33 @@ mod storage;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, vec, Address, BytesN, Env, IntoVal, Map, Symbol,
+    Vec,
+};
 
-pub use bridgelet_shared::{AccountInfo, AccountStatus, Payment};
+pub use bridgelet_shared::{
+    AccountInfo, AccountStatus, AssetRow, Payment, Timeline, TransferInstruction,
+};
 pub use errors::Error;
 pub use events::{
-    AccountCreated, AccountExpired, MultiPaymentReceived, PaymentReceived, SweepExecutedMulti,
+    AccountCreated, AccountExpired, BatchPaymentReceived, CommittedPaymentReceived,
+    MultiPaymentReceived, PaymentReceived, SettlementNotification, SweepExecutedMulti,
 };
 pub use storage::DataKey;
 
+/// Maximum number of distinct assets an account can hold
+const MAX_PAYMENTS: u32 = 10;
+
+/// Maximum number of external references that can be attached to an account
+const MAX_REFERENCES: u32 = 20;
+
+/// Maximum number of ledgers between initialization and expiry (~31 days at 5s per ledger)
+pub const MAX_LIFETIME_LEDGERS: u32 = 535_680;
+
+/// Number of ledgers a sweep approval stays valid (~1 hour at 5s per ledger)
+pub const APPROVAL_WINDOW_LEDGERS: u32 = 720;
+
 #[contract]
 pub struct EphemeralAccountContract;
 
@@ -29,6 +47,7 @@ impl EphemeralAccountContract {
     ///
     /// # Errors
     /// Returns Error::AlreadyInitialized if called more than once
+    /// Returns Error::InvalidExpiry if expiry is not in the future or exceeds MAX_LIFETIME_LEDGERS
     pub fn initialize(
         env: Env,
         creator: Address,
@@ -49,12 +68,19 @@ impl EphemeralAccountContract {
             return Err(Error::InvalidExpiry);
         }
 
+        // Bound how long the account can live
+        if expiry_ledger - current_ledger > MAX_LIFETIME_LEDGERS {
+            return Err(Error::InvalidExpiry);
+        }
+
         // Store initialization data
         storage::set_initialized(&env, true);
         storage::set_creator(&env, &creator);
         storage::set_expiry_ledger(&env, expiry_ledger);
         storage::set_recovery_address(&env, &recovery_address);
         storage::set_status(&env, AccountStatus::Active);
+        storage::set_created_ledger(&env, current_ledger);
+        storage::set_last_activity_ledger(&env, current_ledger);
 
         // Emit event
         events::emit_account_created(&env, creator, expiry_ledger);
@@ -62,52 +88,52 @@ impl EphemeralAccountContract {
         Ok(())
     }
 
-    /// Record an inbound payment to this ephemeral account
-    /// Multiple payments with different assets are supported
+    /// Set the cumulative volume cap across all assets
+    /// Payments that would push the total past the cap are rejected
     ///
     /// # Arguments
-    /// * `amount` - Payment amount
-    /// * `asset` - Asset address
+    /// * `volume_cap` - Maximum cumulative amount, 0 disables the cap
     ///
     /// # Errors
-    /// Returns Error::InvalidAmount if amount is not positive
-    /// Returns Error::DuplicateAsset if asset already has a payment
-    pub fn record_payment(env: Env, amount: i128, asset: Address) -> Result<(), Error> {
+    /// Returns Error::InvalidStatus if a payment has already been recorded
+    /// Returns Error::InvalidAmount if the cap is negative
+    pub fn set_volume_cap(env: Env, volume_cap: i128) -> Result<(), Error> {
         // Check initialized
         if !storage::is_initialized(&env) {
             return Err(Error::NotInitialized);
         }
 
-        // Validate amount
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
 
-        // Check for duplicate asset
-        if storage::get_payment(&env, &asset).is_some() {
-            return Err(Error::DuplicateAsset);
+        // Limits are fixed once funds arrive
+        if storage::get_status(&env) != AccountStatus::Active {
+            return Err(Error::InvalidStatus);
         }
 
-        // Check payment limit to prevent gas issues (max 10 assets)
-        let payment_count = storage::get_total_payments(&env);
-        if payment_count >= 10 {
-            return Err(Error::TooManyPayments);
+        if volume_cap < 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // Create payment with current timestamp
-        let payment = Payment {
-            asset: asset.clone(),
-            amount,
-            timestamp: env.ledger().timestamp(),
-        };
+        storage::set_volume_cap(&env, volume_cap);
 
-        // Add payment
-        storage::add_payment(&env, payment);
+        Ok(())
+    }
 
-        // Update status only on first payment
-        if payment_count == 0 {
-            storage::set_status(&env, AccountStatus::PaymentReceived);
-        }
+    /// Record an inbound payment to this ephemeral account
+    /// Multiple payments with different assets are supported
+    ///
+    /// # Arguments
+    /// * `amount` - Payment amount
+    /// * `asset` - Asset address
+    ///
+    /// # Errors
+    /// Returns Error::InvalidAmount if amount is not positive
+    /// Returns Error::DuplicateAsset if asset already has a payment
+    /// Returns Error::VolumeCapExceeded if the cumulative total would exceed the cap
+    /// Returns Error::AssetNotAllowed if the denylist source denies the asset
+    pub fn record_payment(env: Env, amount: i128, asset: Address) -> Result<(), Error> {
+        let payment_count = Self::store_payment(&env, amount, &asset)?;
 
         // Emit appropriate event
         if payment_count == 0 {
@@ -119,42 +145,113 @@ impl EphemeralAccountContract {
         Ok(())
     }
 
-    /// Execute sweep to destination wallet
-    /// Transfers all funds from all assets to the specified destination atomically
+    /// Record an inbound payment whose recording event carries a commitment instead of the amount
+    /// Only the recording event is affected: the amount is stored in public contract state,
+    /// returned by the read methods and published by the sweep, settlement and expiry events
     ///
     /// # Arguments
-    /// * `destination` - Recipient wallet address
-    /// * `auth_signature` - Authorization signature from off-chain system
+    /// * `amount` - Payment amount
+    /// * `asset` - Asset address
+    /// * `commitment` - Payer-supplied commitment to the amount (e.g. hash of amount and blinding factor)
     ///
     /// # Errors
-    /// Returns Error::Unauthorized if authorization fails
-    /// Returns Error::AlreadySwept if sweep already executed
-    pub fn sweep(env: Env, destination: Address, auth_signature: BytesN<64>) -> Result<(), Error> {
-        // Check initialized
-        if !storage::is_initialized(&env) {
-            return Err(Error::NotInitialized);
+    /// Returns any error `record_payment` would return
+    pub fn record_committed_payment(
+        env: Env,
+        amount: i128,
+        asset: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::store_payment(&env, amount, &asset)?;
+
+        events::emit_committed_payment_received(&env, asset, commitment);
+
+        Ok(())
+    }
+
+    /// Record several inbound payments in one call
+    /// Emits a single batch event instead of one event per payment
+    ///
+    /// # Arguments
+    /// * `entries` - (amount, asset) pairs, validated like `record_payment`
+    ///
+    /// # Errors
+    /// Returns Error::InvalidAmount if the batch is empty
+    /// Returns any error `record_payment` would return for an entry
+    pub fn record_payments(env: Env, entries: Vec<(i128, Address)>) -> Result<(), Error> {
+        if entries.is_empty() {
+            return Err(Error::InvalidAmount);
         }
 
-        // Check not already swept
-        if storage::get_status(&env) == AccountStatus::Swept {
-            return Err(Error::AlreadySwept);
+        let mut totals = Map::new(&env);
+        for (amount, asset) in entries.iter() {
+            Self::store_payment(&env, amount, &asset)?;
+            totals.set(asset, amount);
         }
 
-        // Check payment received
-        if !storage::has_payment_received(&env) {
-            return Err(Error::NoPaymentReceived);
+        events::emit_batch_payment_received(&env, entries.len(), totals);
+
+        Ok(())
+    }
+
+    /// Preflight check for a prospective `record_payment`
+    /// Runs the same validation, including per-asset minimums, without recording
+    /// anything; like `record_payment`, it does not check expiry or status
+    ///
+    /// # Arguments
+    /// * `amount` - Payment amount
+    /// * `asset` - Asset address
+    ///
+    /// # Returns
+    /// 0 if the payment would be accepted, otherwise the `Error` code it would hit
+    pub fn check_record(env: Env, amount: i128, asset: Address) -> u32 {
+        match Self::validate_payment(&env, amount, &asset) {
+            Ok(_) => 0,
+            Err(err) => err as u32,
         }
+    }
 
-        // Check not expired
-        if Self::is_expired(env.clone()) {
-            return Err(Error::AccountExpired);
+    /// Check if at least one more `record_payment` could succeed right now
+    ///
+    /// # Returns
+    /// true if there is a free asset slot and room left under the volume cap
+    pub fn can_record_more(env: Env) -> bool {
+        if !storage::is_initialized(&env) {
+            return false;
+        }
+
+        if storage::get_total_payments(&env) >= MAX_PAYMENTS {
+            return false;
         }
 
+        // The smallest payment is 1, so any room under the cap is enough
+        let volume_cap = storage::get_volume_cap(&env);
+        volume_cap == 0 || storage::get_total_volume(&env) < volume_cap
+    }
+
+    /// Execute sweep to destination wallet
+    /// Transfers all funds from all assets to the specified destination atomically
+    ///
+    /// # Arguments
+    /// * `destination` - Recipient wallet address
+    /// * `auth_signature` - Authorization signature from off-chain system
+    ///
+    /// # Errors
+    /// Returns Error::Unauthorized if authorization fails or, with an approver set, the destination is not approved
+    /// Returns Error::AlreadySwept if sweep already executed
+    /// Returns Error::NotEnoughAssets if fewer distinct assets than required are present
+    pub fn sweep(env: Env, destination: Address, auth_signature: BytesN<64>) -> Result<(), Error> {
+        Self::check_sweepable(&env)?;
+
         // Verify authorization signature
         // Note: In production, implement proper signature verification
         // For MVP, we trust the SDK to only call with valid signatures
         Self::verify_sweep_authorization(&env, &destination, &auth_signature)?;
 
+        // With an approver configured, the sweep needs a matching approval, consumed here
+        Self::check_approval(&env, &destination)?;
+        storage::remove_approved_destination(&env);
+
         // Get all payments
         let payments = storage::get_all_payments(&env);
         let mut payments_vec = Vec::new(&env);
@@ -165,6 +262,8 @@ impl EphemeralAccountContract {
         // Update status before transfer to prevent reentrancy
         storage::set_status(&env, AccountStatus::Swept);
         storage::set_swept_to(&env, &destination);
+        storage::set_swept_ledger(&env, env.ledger().sequence());
+        storage::set_last_activity_ledger(&env, env.ledger().sequence());
 
         // Note: Actual token transfers happen in the SDK via Stellar SDK
         // This contract enforces the business logic and authorization
@@ -172,11 +271,72 @@ impl EphemeralAccountContract {
         // All transfers must succeed or the entire operation fails
 
         // Emit event with all assets
-        events::emit_sweep_executed_multi(&env, destination, &payments_vec);
+        events::emit_sweep_executed_multi(&env, destination.clone(), &payments_vec);
+
+        // Emit a settlement notification per asset for anchor integrations
+        if let Some(settlement_ref) = storage::get_settlement_ref(&env) {
+            for payment in payments_vec.iter() {
+                events::emit_settlement_notification(
+                    &env,
+                    payment.amount,
+                    payment.asset,
+                    destination.clone(),
+                    settlement_ref.clone(),
+                );
+            }
+        }
 
         Ok(())
     }
 
+    /// Preflight check for a prospective `sweep`
+    /// Runs the same state and approval checks without sweeping; the signature is not checked
+    ///
+    /// # Arguments
+    /// * `destination` - Intended recipient, checked against the sweep approval when an approver is set
+    ///
+    /// # Returns
+    /// 0 if the sweep would pass its checks, otherwise the `Error` code it would hit
+    pub fn validate_sweep(env: Env, destination: Address) -> u32 {
+        match Self::check_sweepable(&env).and_then(|_| Self::check_approval(&env, &destination)) {
+            Ok(()) => 0,
+            Err(err) => err as u32,
+        }
+    }
+
+    /// Get the account's current storage footprint for rent monitoring
+    ///
+    /// # Returns
+    /// (entry_count, approx_bytes): instance storage keys in use and the approximate
+    /// XDR size of those keys and their values
+    pub fn storage_footprint(env: Env) -> (u32, u32) {
+        storage::footprint(&env)
+    }
+
+    /// Get the transfers a sweep to `destination` would perform
+    /// One instruction per recorded asset, matching the payments in the sweep event
+    ///
+    /// # Arguments
+    /// * `destination` - Intended recipient
+    ///
+    /// # Errors
+    /// Returns any state or approval error `sweep` would return; the signature is not checked
+    pub fn sweep_plan(env: Env, destination: Address) -> Result<Vec<TransferInstruction>, Error> {
+        Self::check_sweepable(&env)?;
+        Self::check_approval(&env, &destination)?;
+
+        let mut plan = Vec::new(&env);
+        for payment in storage::get_all_payments(&env).values() {
+            plan.push_back(TransferInstruction {
+                asset: payment.asset,
+                to: destination.clone(),
+                amount: payment.amount,
+            });
+        }
+
+        Ok(plan)
+    }
+
     /// Check if account has expired
     pub fn is_expired(env: Env) -> bool {
         if !storage::is_initialized(&env) {
@@ -189,6 +349,22 @@ impl EphemeralAccountContract {
         current_ledger >= expiry_ledger
     }
 
+    /// Get the earliest ledger at which `sweep` can succeed
+    /// The account has no time-lock, so a sweep is never held back to a later ledger
+    ///
+    /// # Returns
+    /// Some(current ledger) if the account is sweepable now or can still become
+    /// sweepable (e.g. unfunded, below the minimum asset count or awaiting approval),
+    /// None if it can never be swept (not initialized, swept or expired)
+    pub fn sweepable_at(env: Env) -> Option<u32> {
+        match Self::check_sweepable(&env) {
+            Err(Error::NotInitialized) | Err(Error::AlreadySwept) | Err(Error::AccountExpired) => {
+                None
+            }
+            _ => Some(env.ledger().sequence()),
+        }
+    }
+
     /// Get current account status
     pub fn get_status(env: Env) -> AccountStatus {
         if !storage::is_initialized(&env) {
@@ -226,6 +402,7 @@ impl EphemeralAccountContract {
         // Update status
         storage::set_status(&env, AccountStatus::Expired);
         storage::set_swept_to(&env, &recovery_address);
+        storage::set_last_activity_ledger(&env, env.ledger().sequence());
 
         // Get total amount from all payments if any payments were received
         let total_amount = if storage::has_payment_received(&env) {
@@ -243,6 +420,245 @@ impl EphemeralAccountContract {
         Ok(())
     }
 
+    /// Set the minimum number of distinct assets required before a sweep
+    ///
+    /// # Arguments
+    /// * `min_assets` - Required asset count, 0 or 1 keeps the default behavior
+    ///
+    /// # Errors
+    /// Returns Error::InvalidStatus if a payment has already been recorded
+    /// Returns Error::InvalidConfig if more assets are required than an account can hold
+    pub fn set_min_assets_to_sweep(env: Env, min_assets: u32) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
+
+        // Sweep requirements are fixed once funds arrive
+        if storage::get_status(&env) != AccountStatus::Active {
+            return Err(Error::InvalidStatus);
+        }
+
+        if min_assets > MAX_PAYMENTS {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_min_assets_to_sweep(&env, min_assets);
+
+        Ok(())
+    }
+
+    /// Set the minimum payment amount for individual assets
+    /// Assets without an entry only need a positive amount
+    ///
+    /// # Arguments
+    /// * `min_per_asset` - Minimum amount per asset, in each asset's own units
+    ///
+    /// # Errors
+    /// Returns Error::InvalidStatus if a payment has already been recorded
+    /// Returns Error::InvalidAmount if a minimum is negative
+    pub fn set_min_per_asset(env: Env, min_per_asset: Map<Address, i128>) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
+
+        // Limits are fixed once funds arrive
+        if storage::get_status(&env) != AccountStatus::Active {
+            return Err(Error::InvalidStatus);
+        }
+
+        for (_, min_amount) in min_per_asset.iter() {
+            if min_amount < 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+
+        storage::set_min_per_asset(&env, &min_per_asset);
+
+        Ok(())
+    }
+
+    /// Set the controller whose asset denylist `record_payment` consults
+    ///
+    /// # Arguments
+    /// * `controller` - Contract exposing `is_denied(asset: Address) -> bool`
+    ///
+    /// # Errors
+    /// Returns Error::InvalidStatus if a payment has already been recorded
+    pub fn set_denylist_source(env: Env, controller: Address) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
+
+        // The source is fixed once funds arrive
+        if storage::get_status(&env) != AccountStatus::Active {
+            return Err(Error::InvalidStatus);
+        }
+
+        storage::set_denylist_source(&env, &controller);
+
+        Ok(())
+    }
+
+    /// Require a second party to approve each sweep
+    /// Changing the approver discards any approval given by the previous one
+    ///
+    /// # Arguments
+    /// * `approver` - Address that must call `approve_sweep` before a sweep
+    ///
+    /// # Errors
+    /// Returns Error::InvalidStatus if a payment has already been recorded
+    /// Returns Error::InvalidConfig if the approver is the creator
+    pub fn set_approver(env: Env, approver: Address) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        let creator = storage::get_creator(&env);
+        creator.require_auth();
+
+        // The approver is fixed once funds arrive
+        if storage::get_status(&env) != AccountStatus::Active {
+            return Err(Error::InvalidStatus);
+        }
+
+        // The approver must be a second party
+        if approver == creator {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_approver(&env, &approver);
+        storage::remove_approved_destination(&env);
+
+        Ok(())
+    }
+
+    /// Approve the next sweep to a specific destination
+    /// The approval replaces any earlier one, is consumed by the sweep and
+    /// lapses after `APPROVAL_WINDOW_LEDGERS`
+    ///
+    /// # Arguments
+    /// * `destination` - The only destination the next sweep may use
+    ///
+    /// # Errors
+    /// Returns Error::InvalidConfig if no approver is configured
+    /// Returns Error::AlreadySwept if the account was already swept
+    pub fn approve_sweep(env: Env, destination: Address) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        let approver = storage::get_approver(&env).ok_or(Error::InvalidConfig)?;
+        approver.require_auth();
+
+        if storage::get_status(&env) == AccountStatus::Swept {
+            return Err(Error::AlreadySwept);
+        }
+
+        let expires_ledger = env.ledger().sequence() + APPROVAL_WINDOW_LEDGERS;
+        storage::set_approved_destination(&env, &destination, expires_ledger);
+
+        Ok(())
+    }
+
+    /// Get the destination the next sweep is approved for, if any
+    /// A lapsed approval is reported as None
+    pub fn get_approved_destination(env: Env) -> Option<Address> {
+        Self::active_approval(&env)
+    }
+
+    /// Set the transaction reference carried by settlement notifications
+    /// Once set, `sweep` emits a `settled` event per swept asset
+    ///
+    /// # Arguments
+    /// * `settlement_ref` - 32-byte settlement transaction reference
+    pub fn set_settlement_ref(env: Env, settlement_ref: BytesN<32>) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
+
+        storage::set_settlement_ref(&env, &settlement_ref);
+
+        Ok(())
+    }
+
+    /// Append an off-chain reference (e.g. a shipment update hash) to the account
+    /// References are kept in the order they were appended
+    ///
+    /// # Arguments
+    /// * `reference` - 32-byte external reference
+    ///
+    /// # Errors
+    /// Returns Error::InvalidConfig if the reference log is full
+    pub fn append_reference(env: Env, reference: BytesN<32>) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify creator authorization
+        storage::get_creator(&env).require_auth();
+
+        let mut references = storage::get_references(&env);
+        if references.len() >= MAX_REFERENCES {
+            return Err(Error::InvalidConfig);
+        }
+
+        references.push_back(reference);
+        storage::set_references(&env, &references);
+
+        Ok(())
+    }
+
+    /// Get all external references in append order
+    pub fn get_references(env: Env) -> Vec<BytesN<32>> {
+        storage::get_references(&env)
+    }
+
+    /// Get which optional features are active for this account
+    ///
+    /// # Returns
+    /// Map with a stable key set: `vol_cap`, `min_assets`, `denylist`, `settle_ref`, `approval`
+    pub fn features(env: Env) -> Map<Symbol, bool> {
+        let mut features = Map::new(&env);
+        features.set(symbol_short!("vol_cap"), storage::get_volume_cap(&env) > 0);
+        features.set(
+            Symbol::new(&env, "min_assets"),
+            storage::get_min_assets_to_sweep(&env) > 1,
+        );
+        features.set(
+            symbol_short!("denylist"),
+            storage::get_denylist_source(&env).is_some(),
+        );
+        features.set(
+            Symbol::new(&env, "settle_ref"),
+            storage::get_settlement_ref(&env).is_some(),
+        );
+        features.set(
+            symbol_short!("approval"),
+            storage::get_approver(&env).is_some(),
+        );
+        features
+    }
+
     /// Get account information
     pub fn get_info(env: Env) -> Result<AccountInfo, Error> {
         if !storage::is_initialized(&env) {
@@ -270,8 +686,185 @@ impl EphemeralAccountContract {
         })
     }
 
+    /// Get a per-asset report of recorded totals and live token balances
+    ///
+    /// # Returns
+    /// One row per recorded asset with the recorded amount, the contract's
+    /// current token balance and whether the asset has been swept
+    pub fn asset_report(env: Env) -> Result<Vec<AssetRow>, Error> {
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        let swept = storage::get_status(&env) == AccountStatus::Swept;
+        let contract_address = env.current_contract_address();
+
+        let mut rows = Vec::new(&env);
+        for payment in storage::get_all_payments(&env).values() {
+            let balance = token::Client::new(&env, &payment.asset).balance(&contract_address);
+            rows.push_back(AssetRow {
+                asset: payment.asset,
+                recorded: payment.amount,
+                balance,
+                swept,
+            });
+        }
+
+        Ok(rows)
+    }
+
+    /// Get the key ledger numbers of the account's lifecycle
+    /// Optional fields are populated once the corresponding event occurs
+    pub fn timeline(env: Env) -> Result<Timeline, Error> {
+        if !storage::is_initialized(&env) {
+            return Err(Error::NotInitialized);
+        }
+
+        Ok(Timeline {
+            created_ledger: storage::get_created_ledger(&env),
+            first_payment_ledger: storage::get_first_payment_ledger(&env),
+            last_activity_ledger: storage::get_last_activity_ledger(&env),
+            expiry_ledger: storage::get_expiry_ledger(&env),
+            swept_ledger: storage::get_swept_ledger(&env),
+        })
+    }
+
     // Private helper functions
 
+    /// Validate and store a payment, updating status and timeline
+    /// Returns the number of payments recorded before this one
+    fn store_payment(env: &Env, amount: i128, asset: &Address) -> Result<u32, Error> {
+        let total_volume = Self::validate_payment(env, amount, asset)?;
+        let payment_count = storage::get_total_payments(env);
+
+        // Create payment with current timestamp
+        let payment = Payment {
+            asset: asset.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        // Add payment
+        storage::add_payment(env, payment);
+        storage::set_total_volume(env, total_volume);
+
+        // Update status only on first payment
+        let current_ledger = env.ledger().sequence();
+        if payment_count == 0 {
+            storage::set_status(env, AccountStatus::PaymentReceived);
+            storage::set_first_payment_ledger(env, current_ledger);
+        }
+        storage::set_last_activity_ledger(env, current_ledger);
+
+        Ok(payment_count)
+    }
+
+    /// Run every check `record_payment` applies before storing a payment
+    /// Returns the cumulative volume including this payment
+    fn validate_payment(env: &Env, amount: i128, asset: &Address) -> Result<i128, Error> {
+        // Check initialized
+        if !storage::is_initialized(env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Validate amount
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Check the asset's own minimum when one is configured
+        if let Some(min_amount) = storage::get_min_per_asset(env).get(asset.clone()) {
+            if amount < min_amount {
+                return Err(Error::BelowMinimum);
+            }
+        }
+
+        // Check the asset against the controller's denylist when one is configured
+        if let Some(source) = storage::get_denylist_source(env) {
+            let denied: bool = env.invoke_contract(
+                &source,
+                &Symbol::new(env, "is_denied"),
+                vec![env, asset.into_val(env)],
+            );
+            if denied {
+                return Err(Error::AssetNotAllowed);
+            }
+        }
+
+        // Check for duplicate asset
+        if storage::get_payment(env, asset).is_some() {
+            return Err(Error::DuplicateAsset);
+        }
+
+        // Check payment limit to prevent gas issues (max 10 assets)
+        let payment_count = storage::get_total_payments(env);
+        if payment_count >= MAX_PAYMENTS {
+            return Err(Error::TooManyPayments);
+        }
+
+        // Check cumulative volume against the cap (0 disables the cap)
+        let total_volume = storage::get_total_volume(env)
+            .checked_add(amount)
+            .ok_or(Error::VolumeCapExceeded)?;
+        let volume_cap = storage::get_volume_cap(env);
+        if volume_cap > 0 && total_volume > volume_cap {
+            return Err(Error::VolumeCapExceeded);
+        }
+
+        Ok(total_volume)
+    }
+
+    /// Run every state check `sweep` applies before verifying authorization
+    fn check_sweepable(env: &Env) -> Result<(), Error> {
+        // Check initialized
+        if !storage::is_initialized(env) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Check not already swept
+        if storage::get_status(env) == AccountStatus::Swept {
+            return Err(Error::AlreadySwept);
+        }
+
+        // Check payment received
+        if !storage::has_payment_received(env) {
+            return Err(Error::NoPaymentReceived);
+        }
+
+        // Check enough distinct assets are present
+        if storage::get_total_payments(env) < storage::get_min_assets_to_sweep(env) {
+            return Err(Error::NotEnoughAssets);
+        }
+
+        // Check not expired
+        if Self::is_expired(env.clone()) {
+            return Err(Error::AccountExpired);
+        }
+
+        Ok(())
+    }
+
+    /// Check `destination` against the sweep approval when an approver is configured
+    fn check_approval(env: &Env, destination: &Address) -> Result<(), Error> {
+        if storage::get_approver(env).is_some()
+            && Self::active_approval(env) != Some(destination.clone())
+        {
+            return Err(Error::Unauthorized);
+        }
+
+        Ok(())
+    }
+
+    /// Get the approved destination while its approval window is open
+    fn active_approval(env: &Env) -> Option<Address> {
+        match storage::get_approved_destination(env) {
+            Some((destination, expires_ledger)) if env.ledger().sequence() < expires_ledger => {
+                Some(destination)
+            }
+            _ => None,
+        }
+    }
+
     fn verify_sweep_authorization(
         _env: &Env,
         _destination: &Address,
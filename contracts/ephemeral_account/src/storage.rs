@@ -1,5 +1,5 @@
 use bridgelet_shared::{AccountStatus, Payment};
-use soroban_sdk::{contracttype, Address, Env, Map};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, Map, Val, Vec};
 
 #[contracttype]
 pub enum DataKey {
@@ -10,6 +10,19 @@ pub enum DataKey {
     Payments,
     Status,
     SweptTo,
+    VolumeCap,
+    TotalVolume,
+    References,
+    MinAssetsToSweep,
+    CreatedLedger,
+    FirstPaymentLedger,
+    LastActivityLedger,
+    SweptLedger,
+    SettlementRef,
+    DenylistSource,
+    Approver,
+    ApprovedDestination,
+    MinPerAsset,
 }
 
 // Initialization
@@ -113,3 +126,199 @@ pub fn set_swept_to(env: &Env, address: &Address) {
 pub fn get_swept_to(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::SweptTo)
 }
+
+// Volume cap
+pub fn set_volume_cap(env: &Env, cap: i128) {
+    env.storage().instance().set(&DataKey::VolumeCap, &cap);
+}
+
+pub fn get_volume_cap(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::VolumeCap)
+        .unwrap_or(0)
+}
+
+pub fn set_total_volume(env: &Env, total: i128) {
+    env.storage().instance().set(&DataKey::TotalVolume, &total);
+}
+
+pub fn get_total_volume(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalVolume)
+        .unwrap_or(0)
+}
+
+// External references
+pub fn get_references(env: &Env) -> Vec<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::References)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_references(env: &Env, references: &Vec<BytesN<32>>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::References, references);
+}
+
+// Minimum assets to sweep
+pub fn set_min_assets_to_sweep(env: &Env, min_assets: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinAssetsToSweep, &min_assets);
+}
+
+pub fn get_min_assets_to_sweep(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinAssetsToSweep)
+        .unwrap_or(0)
+}
+
+// Per-asset minimum payment
+pub fn set_min_per_asset(env: &Env, min_per_asset: &Map<Address, i128>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinPerAsset, min_per_asset);
+}
+
+pub fn get_min_per_asset(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinPerAsset)
+        .unwrap_or(Map::new(env))
+}
+
+// Timeline
+pub fn set_created_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CreatedLedger, &ledger);
+}
+
+pub fn get_created_ledger(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatedLedger)
+        .unwrap()
+}
+
+pub fn set_first_payment_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FirstPaymentLedger, &ledger);
+}
+
+pub fn get_first_payment_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::FirstPaymentLedger)
+}
+
+pub fn set_last_activity_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::LastActivityLedger, &ledger);
+}
+
+pub fn get_last_activity_ledger(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LastActivityLedger)
+        .unwrap()
+}
+
+pub fn set_swept_ledger(env: &Env, ledger: u32) {
+    env.storage().instance().set(&DataKey::SweptLedger, &ledger);
+}
+
+pub fn get_swept_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::SweptLedger)
+}
+
+// Settlement reference
+pub fn set_settlement_ref(env: &Env, settlement_ref: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementRef, settlement_ref);
+}
+
+pub fn get_settlement_ref(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::SettlementRef)
+}
+
+// Denylist source
+pub fn set_denylist_source(env: &Env, source: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DenylistSource, source);
+}
+
+pub fn get_denylist_source(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::DenylistSource)
+}
+
+// Sweep approval
+pub fn set_approver(env: &Env, approver: &Address) {
+    env.storage().instance().set(&DataKey::Approver, approver);
+}
+
+pub fn get_approver(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Approver)
+}
+
+pub fn set_approved_destination(env: &Env, destination: &Address, expires_ledger: u32) {
+    env.storage().instance().set(
+        &DataKey::ApprovedDestination,
+        &(destination.clone(), expires_ledger),
+    );
+}
+
+pub fn get_approved_destination(env: &Env) -> Option<(Address, u32)> {
+    env.storage().instance().get(&DataKey::ApprovedDestination)
+}
+
+pub fn remove_approved_destination(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::ApprovedDestination);
+}
+
+// Footprint
+/// Count the instance storage keys in use and the approximate XDR size of their keys and values
+pub fn footprint(env: &Env) -> (u32, u32) {
+    let keys = [
+        DataKey::Initialized,
+        DataKey::Creator,
+        DataKey::ExpiryLedger,
+        DataKey::RecoveryAddress,
+        DataKey::Payments,
+        DataKey::Status,
+        DataKey::SweptTo,
+        DataKey::VolumeCap,
+        DataKey::TotalVolume,
+        DataKey::References,
+        DataKey::MinAssetsToSweep,
+        DataKey::CreatedLedger,
+        DataKey::FirstPaymentLedger,
+        DataKey::LastActivityLedger,
+        DataKey::SweptLedger,
+        DataKey::SettlementRef,
+        DataKey::DenylistSource,
+        DataKey::Approver,
+        DataKey::ApprovedDestination,
+        DataKey::MinPerAsset,
+    ];
+
+    let mut entry_count = 0;
+    let mut approx_bytes = 0;
+    for key in keys {
+        if let Some(value) = env.storage().instance().get::<DataKey, Val>(&key) {
+            entry_count += 1;
+            approx_bytes += key.to_xdr(env).len() + value.to_xdr(env).len();
+        }
+    }
+
+    (entry_count, approx_bytes)
+}
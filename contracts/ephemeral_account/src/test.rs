@@ -1,7 +1,15 @@
 #[cfg(test)]
 mod test {
-    use crate::{AccountStatus, EphemeralAccountContract, EphemeralAccountContractClient};
-    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+    use crate::{
+        AccountStatus, BatchPaymentReceived, CommittedPaymentReceived, EphemeralAccountContract,
+        EphemeralAccountContractClient, Error, SettlementNotification, SweepExecutedMulti,
+        TransferInstruction, APPROVAL_WINDOW_LEDGERS, MAX_LIFETIME_LEDGERS,
+    };
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
+        token, Address, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Vec,
+    };
 
     #[test]
     fn test_initialize() {
@@ -21,6 +29,38 @@ mod test {
         assert_eq!(client.is_expired(), false);
     }
 
+    #[test]
+    fn test_initialize_at_max_lifetime() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + MAX_LIFETIME_LEDGERS;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        assert_eq!(client.get_status(), AccountStatus::Active);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // InvalidExpiry
+    fn test_initialize_beyond_max_lifetime() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + MAX_LIFETIME_LEDGERS + 1;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+    }
+
     #[test]
     fn test_record_payment() {
         let env = Env::default();
@@ -194,4 +234,935 @@ mod test {
 
         // Verify events were published (check env.events())
     }
+
+    #[test]
+    fn test_volume_cap_not_exceeded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_volume_cap(&300);
+
+        let asset1 = Address::generate(&env);
+        let asset2 = Address::generate(&env);
+
+        // Reaching the cap exactly is allowed
+        client.record_payment(&100, &asset1);
+        client.record_payment(&200, &asset2);
+
+        let info = client.get_info();
+        assert_eq!(info.payment_count, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")] // VolumeCapExceeded
+    fn test_volume_cap_exceeded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_volume_cap(&300);
+
+        let asset1 = Address::generate(&env);
+        let asset2 = Address::generate(&env);
+
+        client.record_payment(&100, &asset1);
+        client.record_payment(&201, &asset2); // Should fail - total 301 exceeds cap
+    }
+
+    #[test]
+    fn test_check_record() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        // NotInitialized
+        assert_eq!(client.check_record(&100, &asset), 2);
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_volume_cap(&2000);
+
+        // Accepted, and the check itself records nothing
+        assert_eq!(client.check_record(&100, &asset), 0);
+        assert_eq!(client.get_info().payment_count, 0);
+
+        // InvalidAmount
+        assert_eq!(client.check_record(&0, &asset), 4);
+
+        // VolumeCapExceeded
+        assert_eq!(client.check_record(&2001, &asset), 15);
+
+        // DuplicateAsset
+        client.record_payment(&100, &asset);
+        assert_eq!(client.check_record(&100, &asset), 13);
+
+        // TooManyPayments
+        for _ in 0..9 {
+            client.record_payment(&100, &Address::generate(&env));
+        }
+        assert_eq!(client.check_record(&100, &Address::generate(&env)), 14);
+    }
+
+    #[test]
+    fn test_sweepable_at() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        // Unfunded accounts can still become sweepable
+        assert_eq!(client.sweepable_at(), Some(env.ledger().sequence()));
+
+        client.record_payment(&100, &asset);
+        assert_eq!(client.sweepable_at(), Some(env.ledger().sequence()));
+
+        // Swept accounts are terminal
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+        assert_eq!(client.sweepable_at(), None);
+    }
+
+    #[test]
+    fn test_sweepable_at_after_expiry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_payment(&100, &asset);
+
+        env.ledger()
+            .with_mut(|li| li.sequence_number = expiry_ledger);
+        assert_eq!(client.sweepable_at(), None);
+    }
+
+    #[test]
+    fn test_sweepable_at_below_min_assets() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_min_assets_to_sweep(&2);
+        client.record_payment(&100, &Address::generate(&env));
+
+        // Waiting for a second asset, not terminal
+        assert_eq!(client.validate_sweep(&Address::generate(&env)), 17);
+        assert_eq!(client.sweepable_at(), Some(env.ledger().sequence()));
+
+        // Uninitialized accounts can never be swept
+        let uninitialized = env.register_contract(None, EphemeralAccountContract);
+        let uninitialized_client = EphemeralAccountContractClient::new(&env, &uninitialized);
+        assert_eq!(uninitialized_client.sweepable_at(), None);
+    }
+
+    #[test]
+    fn test_append_references() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+        client.append_reference(&first);
+        client.record_payment(&100, &asset);
+        client.append_reference(&second);
+
+        // References survive payments and keep their order
+        let references = client.get_references();
+        assert_eq!(references.len(), 2);
+        assert_eq!(references.get(0).unwrap(), first);
+        assert_eq!(references.get(1).unwrap(), second);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #16)")] // InvalidConfig
+    fn test_too_many_references() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        for i in 0..20 {
+            client.append_reference(&BytesN::from_array(&env, &[i as u8; 32]));
+        }
+
+        // 21st should fail
+        client.append_reference(&BytesN::from_array(&env, &[255u8; 32]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")] // NotEnoughAssets
+    fn test_sweep_with_too_few_assets() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_min_assets_to_sweep(&2);
+        client.record_payment(&100, &Address::generate(&env));
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig); // Should fail - only one asset
+    }
+
+    #[test]
+    fn test_limits_fixed_after_payment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_payment(&100, &Address::generate(&env));
+
+        // The creator cannot block a funded sweep or cap a funded account
+        assert_eq!(
+            client.try_set_min_assets_to_sweep(&10),
+            Err(Ok(Error::InvalidStatus))
+        );
+        assert_eq!(
+            client.try_set_volume_cap(&50),
+            Err(Ok(Error::InvalidStatus))
+        );
+        assert_eq!(
+            client.try_set_min_per_asset(&Map::new(&env)),
+            Err(Ok(Error::InvalidStatus))
+        );
+    }
+
+    #[test]
+    fn test_min_per_asset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let xlm = Address::generate(&env);
+        let unmapped = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        // Minimums follow each asset's decimal scale
+        let mut min_per_asset = Map::new(&env);
+        min_per_asset.set(usdc.clone(), 1_000_000);
+        min_per_asset.set(xlm.clone(), 10_000_000);
+
+        let mut negative = min_per_asset.clone();
+        negative.set(unmapped.clone(), -1);
+        assert_eq!(
+            client.try_set_min_per_asset(&negative),
+            Err(Ok(Error::InvalidAmount))
+        );
+
+        client.set_min_per_asset(&min_per_asset);
+
+        // A USDC-sized amount is below the XLM minimum
+        assert_eq!(client.check_record(&1_000_000, &xlm), 19);
+        assert_eq!(
+            client.try_record_payment(&1_000_000, &xlm),
+            Err(Ok(Error::BelowMinimum))
+        );
+        assert_eq!(
+            client.try_record_payment(&999_999, &usdc),
+            Err(Ok(Error::BelowMinimum))
+        );
+
+        client.record_payment(&1_000_000, &usdc);
+        client.record_payment(&10_000_000, &xlm);
+
+        // Unmapped assets only need a positive amount
+        assert_eq!(client.check_record(&1, &unmapped), 0);
+        client.record_payment(&1, &unmapped);
+
+        assert_eq!(client.get_info().payment_count, 3);
+    }
+
+    #[test]
+    fn test_sweep_with_enough_assets() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_min_assets_to_sweep(&2);
+        client.record_payment(&100, &Address::generate(&env));
+        client.record_payment(&200, &Address::generate(&env));
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+
+        assert_eq!(client.get_status(), AccountStatus::Swept);
+    }
+
+    #[test]
+    fn test_timeline() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let created_ledger = env.ledger().sequence();
+        let expiry_ledger = created_ledger + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        let timeline = client.timeline();
+        assert_eq!(timeline.created_ledger, created_ledger);
+        assert_eq!(timeline.first_payment_ledger, None);
+        assert_eq!(timeline.last_activity_ledger, created_ledger);
+        assert_eq!(timeline.expiry_ledger, expiry_ledger);
+        assert_eq!(timeline.swept_ledger, None);
+
+        env.ledger()
+            .with_mut(|li| li.sequence_number = created_ledger + 10);
+        client.record_payment(&100, &Address::generate(&env));
+        env.ledger()
+            .with_mut(|li| li.sequence_number = created_ledger + 20);
+        client.record_payment(&200, &Address::generate(&env));
+
+        let timeline = client.timeline();
+        assert_eq!(timeline.first_payment_ledger, Some(created_ledger + 10));
+        assert_eq!(timeline.last_activity_ledger, created_ledger + 20);
+
+        env.ledger()
+            .with_mut(|li| li.sequence_number = created_ledger + 30);
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+
+        let timeline = client.timeline();
+        assert_eq!(timeline.created_ledger, created_ledger);
+        assert_eq!(timeline.first_payment_ledger, Some(created_ledger + 10));
+        assert_eq!(timeline.last_activity_ledger, created_ledger + 30);
+        assert_eq!(timeline.swept_ledger, Some(created_ledger + 30));
+    }
+
+    #[test]
+    fn test_sweep_settlement_notification() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+        let settlement_ref = BytesN::from_array(&env, &[5u8; 32]);
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_settlement_ref(&settlement_ref);
+        client.record_payment(&100, &asset);
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+
+        let (event_contract, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(event_contract, contract_id);
+        assert_eq!(topics, (symbol_short!("settled"),).into_val(&env));
+        assert_eq!(
+            SettlementNotification::try_from_val(&env, &data).unwrap(),
+            SettlementNotification {
+                amount: 100,
+                asset,
+                destination,
+                settlement_ref,
+            }
+        );
+    }
+
+    #[test]
+    fn test_asset_report() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        let token_admin = Address::generate(&env);
+        let asset1 = env
+            .register_stellar_asset_contract_v2(token_admin.clone())
+            .address();
+        let asset2 = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        token::StellarAssetClient::new(&env, &asset1).mint(&contract_id, &100);
+        token::StellarAssetClient::new(&env, &asset2).mint(&contract_id, &150);
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_payment(&100, &asset1);
+        client.record_payment(&200, &asset2);
+
+        let report = client.asset_report();
+        assert_eq!(report.len(), 2);
+        for row in report.iter() {
+            assert!(!row.swept);
+            if row.asset == asset1 {
+                assert_eq!((row.recorded, row.balance), (100, 100));
+            } else {
+                assert_eq!(row.asset, asset2);
+                assert_eq!((row.recorded, row.balance), (200, 150));
+            }
+        }
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+
+        assert!(client.asset_report().iter().all(|row| row.swept));
+    }
+
+    #[test]
+    fn test_record_payments_batch_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        let mut entries = Vec::new(&env);
+        let mut totals = Map::new(&env);
+        for i in 1..=5 {
+            let asset = Address::generate(&env);
+            entries.push_back((i * 100, asset.clone()));
+            totals.set(asset, i * 100);
+        }
+
+        client.record_payments(&entries);
+
+        // Exactly one summarizing event for the whole batch
+        let events = env.events().all();
+        assert_eq!(events.len(), 1);
+        let (_, topics, data) = events.get(0).unwrap();
+        assert_eq!(topics, (symbol_short!("batch_pay"),).into_val(&env));
+        assert_eq!(
+            BatchPaymentReceived::try_from_val(&env, &data).unwrap(),
+            BatchPaymentReceived { count: 5, totals }
+        );
+
+        assert_eq!(client.get_info().payment_count, 5);
+        assert_eq!(client.get_status(), AccountStatus::PaymentReceived);
+    }
+
+    #[test]
+    fn test_record_committed_payment_hides_amount() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let commitment = BytesN::from_array(&env, &[9u8; 32]);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_committed_payment(&100, &asset, &commitment);
+
+        // The event carries the commitment, not the amount
+        let events = env.events().all();
+        assert_eq!(events.len(), 1);
+        let (_, topics, data) = events.get(0).unwrap();
+        assert_eq!(topics, (symbol_short!("pay_cmt"),).into_val(&env));
+        assert_eq!(
+            CommittedPaymentReceived::try_from_val(&env, &data).unwrap(),
+            CommittedPaymentReceived { asset, commitment }
+        );
+
+        // The amount is still stored for sweeping
+        let info = client.get_info();
+        assert_eq!(info.payments.get(0).unwrap().amount, 100);
+        assert_eq!(client.get_status(), AccountStatus::PaymentReceived);
+    }
+
+    #[test]
+    fn test_sweep_to_approved_destination() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let approver = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&approver);
+        client.record_payment(&100, &asset);
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+
+        // No approval yet
+        assert_eq!(
+            client.try_sweep(&destination, &auth_sig),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        client.approve_sweep(&destination);
+        client.sweep(&destination, &auth_sig);
+
+        assert_eq!(client.get_status(), AccountStatus::Swept);
+    }
+
+    #[test]
+    fn test_lapsed_approval_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 10_000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&Address::generate(&env));
+        client.record_payment(&100, &Address::generate(&env));
+        client.approve_sweep(&destination);
+
+        env.ledger()
+            .with_mut(|li| li.sequence_number += APPROVAL_WINDOW_LEDGERS);
+
+        // Sweep, preflight and plan all treat the approval as gone
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        assert_eq!(client.get_approved_destination(), None);
+        assert_eq!(client.validate_sweep(&destination), 8);
+        assert_eq!(
+            client.try_sweep_plan(&destination),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_sweep(&destination, &auth_sig),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        // A fresh approval opens a new window
+        client.approve_sweep(&destination);
+        client.sweep(&destination, &auth_sig);
+        assert_eq!(client.get_status(), AccountStatus::Swept);
+    }
+
+    #[test]
+    fn test_validate_sweep_checks_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&Address::generate(&env));
+        client.record_payment(&100, &Address::generate(&env));
+
+        // Unauthorized until the destination is approved
+        assert_eq!(client.validate_sweep(&destination), 8);
+        client.approve_sweep(&destination);
+        assert_eq!(client.validate_sweep(&destination), 0);
+        assert_eq!(client.validate_sweep(&Address::generate(&env)), 8);
+    }
+
+    #[test]
+    fn test_set_approver_restrictions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+
+        // The creator cannot approve its own sweeps
+        assert_eq!(
+            client.try_set_approver(&creator),
+            Err(Ok(Error::InvalidConfig))
+        );
+
+        client.set_approver(&Address::generate(&env));
+        client.record_payment(&100, &Address::generate(&env));
+
+        // The approver cannot be replaced once funds arrive
+        assert_eq!(
+            client.try_set_approver(&Address::generate(&env)),
+            Err(Ok(Error::InvalidStatus))
+        );
+    }
+
+    #[test]
+    fn test_changing_approver_discards_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&Address::generate(&env));
+        client.approve_sweep(&destination);
+
+        // The revoked approver's approval no longer counts
+        client.set_approver(&Address::generate(&env));
+        client.record_payment(&100, &Address::generate(&env));
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        assert_eq!(
+            client.try_sweep(&destination, &auth_sig),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #8)")] // Unauthorized
+    fn test_sweep_to_unapproved_destination() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let approver = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&approver);
+        client.record_payment(&100, &asset);
+        client.approve_sweep(&Address::generate(&env));
+
+        // Approval is bound to a different destination
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&Address::generate(&env), &auth_sig);
+    }
+
+    #[test]
+    fn test_storage_footprint_grows() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        let (initial_entries, initial_bytes) = client.storage_footprint();
+        assert!(initial_entries > 0);
+
+        // The first payment adds the payments map and payment bookkeeping
+        client.record_payment(&100, &Address::generate(&env));
+        let (entries, bytes) = client.storage_footprint();
+        assert!(entries > initial_entries);
+        assert!(bytes > initial_bytes);
+
+        // Further payments grow the payments map in place
+        client.record_payment(&200, &Address::generate(&env));
+        let (more_entries, more_bytes) = client.storage_footprint();
+        assert_eq!(more_entries, entries);
+        assert!(more_bytes > bytes);
+    }
+
+    #[test]
+    fn test_sweep_plan_matches_sweep() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_payment(&100, &Address::generate(&env));
+        client.record_payment(&250, &Address::generate(&env));
+
+        let plan = client.sweep_plan(&destination);
+        assert_eq!(plan.len(), 2);
+
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+
+        // The plan lists exactly the payments the sweep event reports
+        let events = env.events().all();
+        let (_, topics, data) = events.get(0).unwrap();
+        assert_eq!(topics, (symbol_short!("swept_mul"),).into_val(&env));
+        let swept = SweepExecutedMulti::try_from_val(&env, &data).unwrap();
+
+        let mut executed = Vec::new(&env);
+        for payment in swept.payments.iter() {
+            executed.push_back(TransferInstruction {
+                asset: payment.asset,
+                to: swept.destination.clone(),
+                amount: payment.amount,
+            });
+        }
+        assert_eq!(plan, executed);
+    }
+
+    #[test]
+    fn test_sweep_plan_requires_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_approver(&Address::generate(&env));
+        client.record_payment(&100, &asset);
+
+        // No plan for a sweep that would be rejected
+        assert_eq!(
+            client.try_sweep_plan(&destination),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        client.approve_sweep(&destination);
+        let plan = client.sweep_plan(&destination);
+        assert_eq!(
+            plan,
+            Vec::from_array(
+                &env,
+                [TransferInstruction {
+                    asset,
+                    to: destination.clone(),
+                    amount: 100,
+                }]
+            )
+        );
+        assert_eq!(
+            client.try_sweep_plan(&Address::generate(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_features() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_denylist_source(&Address::generate(&env));
+        client.set_approver(&Address::generate(&env));
+
+        let mut expected = Map::new(&env);
+        expected.set(symbol_short!("vol_cap"), false);
+        expected.set(Symbol::new(&env, "min_assets"), false);
+        expected.set(symbol_short!("denylist"), true);
+        expected.set(Symbol::new(&env, "settle_ref"), false);
+        expected.set(symbol_short!("approval"), true);
+        assert_eq!(client.features(), expected);
+    }
+
+    #[test]
+    fn test_validate_sweep() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        // NotInitialized
+        assert_eq!(client.validate_sweep(&destination), 2);
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_min_assets_to_sweep(&2);
+
+        // NoPaymentReceived
+        assert_eq!(client.validate_sweep(&destination), 10);
+
+        // NotEnoughAssets
+        client.record_payment(&100, &Address::generate(&env));
+        assert_eq!(client.validate_sweep(&destination), 17);
+
+        // Sweepable
+        client.record_payment(&100, &Address::generate(&env));
+        assert_eq!(client.validate_sweep(&destination), 0);
+
+        // AlreadySwept
+        let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+        client.sweep(&destination, &auth_sig);
+        assert_eq!(client.validate_sweep(&destination), 7);
+    }
+
+    #[test]
+    fn test_validate_sweep_expired() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let destination = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.record_payment(&100, &Address::generate(&env));
+
+        // AccountExpired
+        env.ledger()
+            .with_mut(|li| li.sequence_number = expiry_ledger);
+        assert_eq!(client.validate_sweep(&destination), 11);
+    }
+
+    #[test]
+    fn test_can_record_more() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        assert!(!client.can_record_more());
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        assert!(client.can_record_more());
+
+        // Full once every asset slot is used
+        for _ in 0..10 {
+            client.record_payment(&100, &Address::generate(&env));
+        }
+        assert!(!client.can_record_more());
+    }
+
+    #[test]
+    fn test_can_record_more_volume_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EphemeralAccountContract);
+        let client = EphemeralAccountContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let expiry_ledger = env.ledger().sequence() + 1000;
+
+        client.initialize(&creator, &expiry_ledger, &recovery);
+        client.set_volume_cap(&300);
+
+        client.record_payment(&299, &Address::generate(&env));
+        assert!(client.can_record_more());
+
+        client.record_payment(&1, &Address::generate(&env));
+        assert!(!client.can_record_more());
+    }
 }
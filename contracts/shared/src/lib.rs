@@ -2,4 +2,4 @@
 
 mod types;
 
-pub use types::{AccountInfo, AccountStatus, Payment};
+pub use types::{AccountInfo, AccountStatus, AssetRow, Payment, Timeline, TransferInstruction};
@@ -32,3 +32,33 @@ pub struct AccountInfo {
     pub payments: Vec<Payment>,
     pub swept_to: Option<Address>,
 }
+
+/// Key ledger numbers in an account's lifecycle
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Timeline {
+    pub created_ledger: u32,
+    pub first_payment_ledger: Option<u32>,
+    pub last_activity_ledger: u32,
+    pub expiry_ledger: u32,
+    pub swept_ledger: Option<u32>,
+}
+
+/// Per-asset view combining recorded and on-chain state
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AssetRow {
+    pub asset: Address,
+    pub recorded: i128,
+    pub balance: i128,
+    pub swept: bool,
+}
+
+/// A single token transfer a sweep will perform
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferInstruction {
+    pub asset: Address,
+    pub to: Address,
+    pub amount: i128,
+}
@@ -15,4 +15,6 @@ pub enum Error {
     AuthorizedSignerNotSet = 10,
     InvalidNonce = 11,
     UnauthorizedDestination = 13,
+    AccountWasmNotSet = 14,
+    NonceReserved = 15,
 }
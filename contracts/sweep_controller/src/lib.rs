@@ -6,12 +6,17 @@ mod storage;
 // mod transfers;
 
 use crate::ephemeral_account::Client as EphemeralAccountClient;
-use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, Map,
+    Symbol, Vec,
+};
 
 use authorization::AuthContext;
-use bridgelet_shared::AccountStatus;
 pub use errors::Error;
 
+/// Number of ledgers a nonce reservation is held (~5 minutes at 5s per ledger)
+pub const NONCE_RESERVATION_LEDGERS: u32 = 60;
+
 #[contract]
 pub struct SweepController;
 
@@ -23,6 +28,7 @@ impl SweepController {
     /// * `authorized_signer` - Ed25519 public key (32 bytes) that will authorize sweep operations
     /// * `authorized_destination` - Optional destination address. If provided, sweeps can only go to this address (locked mode).
     ///                              If None, any destination is allowed (flexible mode).
+    /// * `admin` - Address that manages factory, denylist and nonce reservation configuration
     ///
     /// # Errors
     /// Returns Error::AuthorizationFailed if called more than once
@@ -30,6 +36,7 @@ impl SweepController {
         env: Env,
         authorized_signer: BytesN<32>,
         authorized_destination: Option<Address>,
+        admin: Address,
     ) -> Result<(), Error> {
         // Check if already initialized
         if storage::get_authorized_signer(&env).is_some() {
@@ -46,6 +53,9 @@ impl SweepController {
         // Store the authorized signer public key
         storage::set_authorized_signer(&env, &authorized_signer);
 
+        // Store the admin together with the signer so it cannot be claimed later
+        storage::set_admin(&env, &admin);
+
         // Initialize the sweep nonce to 0
         storage::init_sweep_nonce(&env);
 
@@ -70,6 +80,7 @@ impl SweepController {
     /// Returns Error::InvalidAccount if account is not in valid state
     /// Returns Error::TransferFailed if token transfer fails
     /// Returns Error::UnauthorizedDestination if destination doesn't match authorized destination (when set)
+    /// Returns Error::NonceReserved if the nonce is reserved for another account
     pub fn execute_sweep(
         env: Env,
         ephemeral_account: Address,
@@ -85,6 +96,13 @@ impl SweepController {
             }
         }
 
+        // Respect an outstanding reservation of the nonce by another account
+        if let Some(holder) = active_reservation(&env) {
+            if holder != ephemeral_account {
+                return Err(Error::NonceReserved);
+            }
+        }
+
         // Verify authorization
         let auth_ctx = AuthContext::new(
             ephemeral_account.clone(),
@@ -95,6 +113,7 @@ impl SweepController {
 
         // Increment nonce after successful verification to prevent replay attacks
         authorization::increment_nonce(&env);
+        storage::remove_nonce_reservation(&env);
 
         // Call ephemeral account contract to validate and authorize sweep
         // This triggers the account's sweep() method which updates state
@@ -132,16 +151,260 @@ impl SweepController {
         Ok(())
     }
 
+    /// Set the ephemeral account wasm hash used by `deploy_account`
+    ///
+    /// # Arguments
+    /// * `wasm_hash` - Hash of the uploaded ephemeral account wasm
+    ///
+    /// # Errors
+    /// Returns Error::AuthorizationFailed if no admin is set or caller is not the admin
+    pub fn set_account_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        // Verify admin authorization
+        require_admin(&env)?;
+
+        storage::set_account_wasm_hash(&env, &wasm_hash);
+
+        Ok(())
+    }
+
+    /// Deploy and initialize an ephemeral account at a deterministic address
+    ///
+    /// The deployer salt is `sha256(creator.to_xdr() || salt)`, so the address is
+    /// bound to this controller, the creator and the salt, and can be predicted with
+    /// `account_address`. Another creator cannot claim the same address, and
+    /// reusing a salt for the same creator fails. The account consults this
+    /// controller's asset denylist.
+    ///
+    /// # Arguments
+    /// * `salt` - Salt the account address is derived from
+    /// * `creator` - Address that created the account
+    /// * `expiry_ledger` - Ledger number when the account expires
+    /// * `recovery_address` - Address to return funds if expired
+    ///
+    /// # Errors
+    /// Returns Error::AccountWasmNotSet if no account wasm hash is configured
+    pub fn deploy_account(
+        env: Env,
+        salt: BytesN<32>,
+        creator: Address,
+        expiry_ledger: u32,
+        recovery_address: Address,
+    ) -> Result<Address, Error> {
+        // The creator authorizes the deployment and the account's configuration
+        creator.require_auth();
+
+        let wasm_hash = storage::get_account_wasm_hash(&env).ok_or(Error::AccountWasmNotSet)?;
+
+        let account = env
+            .deployer()
+            .with_current_contract(account_salt(&env, &creator, &salt))
+            .deploy_v2(wasm_hash, ());
+
+        let account_client = EphemeralAccountClient::new(&env, &account);
+        account_client.initialize(&creator, &expiry_ledger, &recovery_address);
+        account_client.set_denylist_source(&env.current_contract_address());
+
+        Ok(account)
+    }
+
+    /// Get the address `deploy_account` would deploy to for a creator and salt
+    ///
+    /// # Arguments
+    /// * `creator` - Address that will create the account
+    /// * `salt` - Salt passed to `deploy_account`
+    pub fn account_address(env: Env, creator: Address, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(account_salt(&env, &creator, &salt))
+            .deployed_address()
+    }
+
+    /// Add or remove an asset from the global denylist consulted by managed accounts
+    ///
+    /// # Arguments
+    /// * `asset` - Asset contract address
+    /// * `denied` - true to deny the asset, false to allow it again
+    ///
+    /// # Errors
+    /// Returns Error::AuthorizationFailed if no admin is set or caller is not the admin
+    pub fn set_asset_denied(env: Env, asset: Address, denied: bool) -> Result<(), Error> {
+        // Verify admin authorization
+        require_admin(&env)?;
+
+        storage::set_asset_denied(&env, &asset, denied);
+
+        Ok(())
+    }
+
+    /// Check if an asset is on the global denylist
+    pub fn is_denied(env: Env, asset: Address) -> bool {
+        storage::is_asset_denied(&env, &asset)
+    }
+
     /// Check if an account is ready for sweep
     pub fn can_sweep(env: Env, ephemeral_account: Address) -> bool {
-        let account_client = EphemeralAccountClient::new(&env, &ephemeral_account);
+        sweepable_info(&env, &ephemeral_account).is_some()
+    }
 
-        // Check if account exists and has payment
-        let info = account_client.get_info();
+    /// Sum recorded amounts per asset across the accounts that can be swept now
+    ///
+    /// # Arguments
+    /// * `accounts` - Ephemeral account addresses to consider
+    ///
+    /// # Returns
+    /// Map of asset address to the total sweepable amount, capped at `i128::MAX`
+    pub fn total_sweepable(env: Env, accounts: Vec<Address>) -> Map<Address, i128> {
+        let mut totals = Map::new(&env);
+
+        for account in accounts.iter() {
+            if let Some(info) = sweepable_info(&env, &account) {
+                for payment in info.payments.iter() {
+                    let total: i128 = totals.get(payment.asset.clone()).unwrap_or(0);
+                    let total = total.checked_add(payment.amount).unwrap_or(i128::MAX);
+                    totals.set(payment.asset, total);
+                }
+            }
+        }
+
+        totals
+    }
+
+    /// Count accounts by status
+    ///
+    /// # Arguments
+    /// * `accounts` - Ephemeral account addresses to count
+    ///
+    /// # Returns
+    /// Map of status symbol (`active`, `paid`, `swept`, `expired`) to account count.
+    /// Statuses with no accounts are omitted, and addresses that are not
+    /// initialized ephemeral accounts are not counted.
+    pub fn status_histogram(env: Env, accounts: Vec<Address>) -> Map<Symbol, u32> {
+        let mut counts = Map::new(&env);
+
+        for account in accounts.iter() {
+            let info = match EphemeralAccountClient::new(&env, &account).try_get_info() {
+                Ok(Ok(info)) => info,
+                _ => continue,
+            };
+            let status = match info.status {
+                ephemeral_account::AccountStatus::Active => symbol_short!("active"),
+                ephemeral_account::AccountStatus::PaymentReceived => symbol_short!("paid"),
+                ephemeral_account::AccountStatus::Swept => symbol_short!("swept"),
+                ephemeral_account::AccountStatus::Expired => symbol_short!("expired"),
+            };
+            let count = counts.get(status.clone()).unwrap_or(0);
+            counts.set(status, count + 1);
+        }
+
+        counts
+    }
 
-        info.payment_received
-            && info.status as u32 == AccountStatus::PaymentReceived as u32
-            && !account_client.is_expired()
+    /// Check whether two accounts were created by the same creator
+    ///
+    /// # Arguments
+    /// * `a` - First ephemeral account address
+    /// * `b` - Second ephemeral account address
+    ///
+    /// # Errors
+    /// Returns Error::InvalidAccount if either address is not an initialized ephemeral account
+    pub fn same_creator(env: Env, a: Address, b: Address) -> Result<bool, Error> {
+        Ok(account_creator(&env, &a)? == account_creator(&env, &b)?)
+    }
+
+    /// Order accounts by total recorded value, highest first
+    ///
+    /// Ties are broken by ascending address so the order is deterministic.
+    /// Addresses whose total cannot be read are ranked last.
+    ///
+    /// # Arguments
+    /// * `accounts` - Ephemeral account addresses to order
+    pub fn prioritized_accounts(env: Env, accounts: Vec<Address>) -> Vec<Address> {
+        let mut ordered: Vec<Address> = Vec::new(&env);
+        let mut totals: Vec<Option<i128>> = Vec::new(&env);
+
+        for account in accounts.iter() {
+            let total = account_total(&env, &account);
+
+            // Find the first position this account ranks ahead of; None sorts below any total
+            let mut index = 0;
+            while index < ordered.len() {
+                let other_total = totals.get_unchecked(index);
+                if total > other_total
+                    || (total == other_total && account < ordered.get_unchecked(index))
+                {
+                    break;
+                }
+                index += 1;
+            }
+
+            ordered.insert(index, account);
+            totals.insert(index, total);
+        }
+
+        ordered
+    }
+
+    /// Check whether a sweep nonce has already been consumed
+    ///
+    /// # Arguments
+    /// * `nonce` - Nonce to check
+    ///
+    /// # Returns
+    /// true if the nonce is below the current sweep nonce, false otherwise
+    pub fn is_nonce_used(env: Env, nonce: u64) -> bool {
+        nonce < storage::get_sweep_nonce(&env)
+    }
+
+    /// Reserve the next sweep nonce for an account
+    ///
+    /// While the reservation is held, `execute_sweep` only accepts a sweep of that account.
+    /// The reservation is released by the sweep or lapses after NONCE_RESERVATION_LEDGERS,
+    /// so a crashed signer does not block others. Reserving again for the same account
+    /// refreshes the hold.
+    ///
+    /// # Arguments
+    /// * `account` - Ephemeral account the signer is preparing a sweep for
+    ///
+    /// # Returns
+    /// The nonce the signer should sign with
+    ///
+    /// # Errors
+    /// Returns Error::AuthorizationFailed if no admin is set or caller is not the admin
+    /// Returns Error::NonceReserved if another account holds an unexpired reservation
+    pub fn reserve_nonce(env: Env, account: Address) -> Result<u64, Error> {
+        // Verify admin authorization
+        require_admin(&env)?;
+
+        if let Some(holder) = active_reservation(&env) {
+            if holder != account {
+                return Err(Error::NonceReserved);
+            }
+        }
+
+        let expires_ledger = env.ledger().sequence() + NONCE_RESERVATION_LEDGERS;
+        storage::set_nonce_reservation(&env, &account, expires_ledger);
+
+        Ok(storage::get_sweep_nonce(&env))
+    }
+
+    /// Get the authorization configuration for sweeps
+    ///
+    /// # Returns
+    /// The placeholder creator, admin, Ed25519 signer key, optional locked destination and next sweep nonce
+    ///
+    /// # Errors
+    /// Returns Error::AuthorizedSignerNotSet if the controller is not initialized
+    pub fn auth_config(env: Env) -> Result<AuthConfig, Error> {
+        let authorized_signer =
+            storage::get_authorized_signer(&env).ok_or(Error::AuthorizedSignerNotSet)?;
+        let creator = storage::get_creator(&env).ok_or(Error::AuthorizedSignerNotSet)?;
+
+        Ok(AuthConfig {
+            creator,
+            admin: storage::get_admin(&env),
+            authorized_signer,
+            authorized_destination: storage::get_authorized_destination(&env),
+            sweep_nonce: storage::get_sweep_nonce(&env),
+        })
     }
 
     /// Update the authorized destination address
@@ -177,6 +440,90 @@ impl SweepController {
     }
 }
 
+/// Account holding an unexpired nonce reservation, if any
+fn active_reservation(env: &Env) -> Option<Address> {
+    match storage::get_nonce_reservation(env) {
+        Some((account, expires_ledger)) if env.ledger().sequence() < expires_ledger => {
+            Some(account)
+        }
+        _ => None,
+    }
+}
+
+/// Verify the admin authorized the current call
+fn require_admin(env: &Env) -> Result<(), Error> {
+    let admin = storage::get_admin(env).ok_or(Error::AuthorizationFailed)?;
+    admin.require_auth();
+    Ok(())
+}
+
+/// Deployer salt for a factory account, binding the address to its creator
+fn account_salt(env: &Env, creator: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = creator.clone().to_xdr(env);
+    preimage.append(&salt.clone().into());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Fetch an ephemeral account's info if it is ready for sweep
+///
+/// Readiness is the account's own `validate_sweep`, checked against the destination
+/// a sweep would use: the locked destination, otherwise the account's approved one.
+/// Addresses that are not initialized accounts are treated as not ready.
+fn sweepable_info(env: &Env, account: &Address) -> Option<ephemeral_account::AccountInfo> {
+    let account_client = EphemeralAccountClient::new(env, account);
+
+    let info = match account_client.try_get_info() {
+        Ok(Ok(info)) => info,
+        _ => return None,
+    };
+
+    // Any address will do when the account has no approval to match
+    let destination = storage::get_authorized_destination(env)
+        .or_else(|| account_client.get_approved_destination())
+        .unwrap_or_else(|| env.current_contract_address());
+
+    if account_client.validate_sweep(&destination) == 0 {
+        Some(info)
+    } else {
+        None
+    }
+}
+
+/// Creator of an ephemeral account, if the address is an initialized account
+fn account_creator(env: &Env, account: &Address) -> Result<Address, Error> {
+    match EphemeralAccountClient::new(env, account).try_get_info() {
+        Ok(Ok(info)) => Ok(info.creator),
+        _ => Err(Error::InvalidAccount),
+    }
+}
+
+/// Sum of all payment amounts recorded by an ephemeral account
+///
+/// None if the address is not an initialized account or the sum overflows
+fn account_total(env: &Env, account: &Address) -> Option<i128> {
+    let info = match EphemeralAccountClient::new(env, account).try_get_info() {
+        Ok(Ok(info)) => info,
+        _ => return None,
+    };
+
+    info.payments
+        .iter()
+        .try_fold(0i128, |total, payment| total.checked_add(payment.amount))
+}
+
+/// Authorization configuration for sweeps
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthConfig {
+    /// Placeholder creator recorded at initialization (the controller's own address)
+    pub creator: Address,
+    /// Admin authorizing factory, denylist and nonce reservation configuration
+    pub admin: Option<Address>,
+    pub authorized_signer: BytesN<32>,
+    pub authorized_destination: Option<Address>,
+    pub sweep_nonce: u64,
+}
+
 /// Sweep completed event
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -207,14 +554,12 @@ fn emit_sweep_completed(env: &Env, account: Address, destination: Address, amoun
         destination,
         amount,
     };
-    env.events()
-        .publish((soroban_sdk::symbol_short!("sweep"),), event);
+    env.events().publish((symbol_short!("sweep"),), event);
 }
 
 fn emit_destination_authorized(env: &Env, destination: Address) {
     let event = DestinationAuthorized { destination };
-    env.events()
-        .publish((soroban_sdk::symbol_short!("dest_auth"),), event);
+    env.events().publish((symbol_short!("dest_auth"),), event);
 }
 
 fn emit_destination_updated(env: &Env, old_destination: Option<Address>, new_destination: Address) {
@@ -222,8 +567,7 @@ fn emit_destination_updated(env: &Env, old_destination: Option<Address>, new_des
         old_destination,
         new_destination,
     };
-    env.events()
-        .publish((soroban_sdk::symbol_short!("dest_upd"),), event);
+    env.events().publish((symbol_short!("dest_upd"),), event);
 }
 
 // Re-export ephemeral_account types for cross-contract calls
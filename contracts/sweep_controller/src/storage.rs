@@ -1,5 +1,11 @@
 use soroban_sdk::{contracttype, Address, BytesN, Env};
 
+/// Ledgers of remaining TTL below which a denylist entry is extended (~1 day)
+const DENYLIST_TTL_THRESHOLD: u32 = 17_280;
+
+/// Ledgers a denylist entry's TTL is extended to (~30 days)
+const DENYLIST_TTL_EXTEND_TO: u32 = 518_400;
+
 /// Data keys for contract storage
 #[contracttype]
 #[derive(Clone)]
@@ -12,6 +18,14 @@ pub enum DataKey {
     AuthorizedDestination,
     /// Creator address (the address that initialized the contract)
     Creator,
+    /// Admin address allowed to manage factory and denylist configuration
+    Admin,
+    /// Wasm hash of the ephemeral account contract deployed by the factory
+    AccountWasmHash,
+    /// Asset that managed accounts must not accept
+    DeniedAsset(Address),
+    /// Account holding the next sweep nonce and the ledger the hold expires at
+    NonceReservation,
 }
 
 /// Set the authorized signer public key
@@ -125,3 +139,117 @@ pub fn set_creator(env: &Env, creator: &Address) {
 pub fn get_creator(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::Creator)
 }
+
+/// Set the admin address
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `admin` - Admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Get the admin address
+///
+/// # Arguments
+/// * `env` - Soroban environment
+///
+/// # Returns
+/// The admin address, or None if not set
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the ephemeral account wasm hash used by the factory
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `wasm_hash` - Hash of the uploaded ephemeral account wasm
+pub fn set_account_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccountWasmHash, wasm_hash);
+}
+
+/// Get the ephemeral account wasm hash used by the factory
+///
+/// # Arguments
+/// * `env` - Soroban environment
+///
+/// # Returns
+/// The ephemeral account wasm hash, or None if not set
+pub fn get_account_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::AccountWasmHash)
+}
+
+/// Add or remove an asset from the global denylist
+///
+/// Entries live in persistent storage so the denylist is not loaded on every call
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `asset` - Asset contract address
+/// * `denied` - true to deny the asset, false to allow it again
+pub fn set_asset_denied(env: &Env, asset: &Address, denied: bool) {
+    let key = DataKey::DeniedAsset(asset.clone());
+    if denied {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, DENYLIST_TTL_THRESHOLD, DENYLIST_TTL_EXTEND_TO);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check if an asset is on the global denylist, extending the entry's TTL if it is
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `asset` - Asset contract address
+///
+/// # Returns
+/// true if the asset is denied, false otherwise
+pub fn is_asset_denied(env: &Env, asset: &Address) -> bool {
+    let key = DataKey::DeniedAsset(asset.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, DENYLIST_TTL_THRESHOLD, DENYLIST_TTL_EXTEND_TO);
+    true
+}
+
+/// Reserve the next sweep nonce for an account
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `account` - Ephemeral account the nonce is reserved for
+/// * `expires_ledger` - Ledger at which the reservation lapses
+pub fn set_nonce_reservation(env: &Env, account: &Address, expires_ledger: u32) {
+    env.storage().instance().set(
+        &DataKey::NonceReservation,
+        &(account.clone(), expires_ledger),
+    );
+}
+
+/// Get the current nonce reservation
+///
+/// # Arguments
+/// * `env` - Soroban environment
+///
+/// # Returns
+/// The reserving account and expiry ledger, or None if nothing is reserved
+pub fn get_nonce_reservation(env: &Env) -> Option<(Address, u32)> {
+    env.storage().instance().get(&DataKey::NonceReservation)
+}
+
+/// Clear the current nonce reservation
+///
+/// # Arguments
+/// * `env` - Soroban environment
+pub fn remove_nonce_reservation(env: &Env) {
+    env.storage().instance().remove(&DataKey::NonceReservation);
+}
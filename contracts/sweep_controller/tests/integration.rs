@@ -1,9 +1,25 @@
 #![cfg(test)]
 
-use ephemeral_account::{AccountStatus, EphemeralAccountContract, EphemeralAccountContractClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use ed25519_dalek::{Signer, SigningKey};
+use ephemeral_account::{
+    AccountStatus, EphemeralAccountContract, EphemeralAccountContractClient, Error as AccountError,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Map, Vec,
+};
 use sweep_controller::Error;
-use sweep_controller::{SweepController, SweepControllerClient};
+use sweep_controller::{
+    AuthConfig, SweepController, SweepControllerClient, NONCE_RESERVATION_LEDGERS,
+};
+
+mod ephemeral_account_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/ephemeral_account.wasm"
+    );
+}
 
 /// Helper function to generate a valid Ed25519 keypair for testing
 /// In a real scenario, these would be generated by the off-chain system
@@ -25,6 +41,31 @@ fn generate_test_keypair() -> (BytesN<32>, BytesN<64>) {
     (public_key, dummy_signature)
 }
 
+/// Helper function to build a deterministic Ed25519 signing key and its public key
+fn generate_signing_key(env: &Env) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+/// Helper function to sign a sweep authorization the way the off-chain system does:
+/// sign(sha256(destination || nonce || contract_id))
+fn sign_sweep(
+    env: &Env,
+    signing_key: &SigningKey,
+    controller_id: &Address,
+    destination: &Address,
+    nonce: u64,
+) -> BytesN<64> {
+    let mut message = Bytes::new(env);
+    message.append(&destination.clone().to_xdr(env));
+    message.extend_from_array(&nonce.to_be_bytes());
+    message.append(&controller_id.clone().to_xdr(env));
+    let hash = env.crypto().sha256(&message).to_array();
+
+    BytesN::from_array(env, &signing_key.sign(&hash).to_bytes())
+}
+
 /// Test successful initialization of sweep controller
 #[test]
 fn test_initialize_sweep_controller() {
@@ -37,7 +78,7 @@ fn test_initialize_sweep_controller() {
     let (authorized_signer, _) = generate_test_keypair();
 
     // Initialize controller with authorized signer (flexible mode - no destination)
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 }
 
 /// Test that re-initialization is prevented
@@ -52,11 +93,11 @@ fn test_initialize_prevents_double_init() {
     let (authorized_signer, _) = generate_test_keypair();
 
     // First initialization should succeed
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 
     // Second initialization should fail
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        controller_client.initialize(&authorized_signer, &None);
+        controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
     }));
     assert!(result.is_err());
 }
@@ -72,7 +113,7 @@ fn test_execute_sweep_with_valid_signature() {
     let controller_client = SweepControllerClient::new(&env, &controller_id);
 
     let (authorized_signer, _) = generate_test_keypair();
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 
     // Deploy ephemeral account
     let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
@@ -114,7 +155,7 @@ fn test_execute_sweep_with_invalid_signature() {
     let controller_client = SweepControllerClient::new(&env, &controller_id);
 
     let (authorized_signer, _) = generate_test_keypair();
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 
     // Deploy ephemeral account
     let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
@@ -185,7 +226,7 @@ fn test_nonce_increment_prevents_replay() {
     let controller_client = SweepControllerClient::new(&env, &controller_id);
 
     let (authorized_signer, _) = generate_test_keypair();
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 
     // The nonce system is in place and will be incremented after each successful
     // authorization, making the same signature invalid for the next sweep operation
@@ -236,7 +277,7 @@ fn test_wrong_signer_rejected() {
     let controller_client = SweepControllerClient::new(&env, &controller_id);
 
     let (authorized_signer, _) = generate_test_keypair();
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 
     // Generate a different public key (wrong signer)
     let wrong_signer = BytesN::from_array(
@@ -321,7 +362,11 @@ fn test_initialize_with_authorized_destination() {
     let authorized_dest = Address::generate(&env);
 
     // Initialize controller with authorized destination
-    controller_client.initialize(&authorized_signer, &Some(authorized_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(authorized_dest.clone()),
+        &Address::generate(&env),
+    );
 }
 
 /// Test initialization without authorized destination (flexible mode)
@@ -336,7 +381,7 @@ fn test_initialize_without_authorized_destination() {
     let (authorized_signer, _) = generate_test_keypair();
 
     // Initialize controller without authorized destination (flexible mode)
-    controller_client.initialize(&authorized_signer, &None);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
 }
 
 /// Test sweep to authorized destination (success)
@@ -351,7 +396,11 @@ fn test_sweep_to_authorized_destination() {
 
     let (authorized_signer, _) = generate_test_keypair();
     let authorized_dest = Address::generate(&env);
-    controller_client.initialize(&authorized_signer, &Some(authorized_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(authorized_dest.clone()),
+        &Address::generate(&env),
+    );
 
     // Deploy ephemeral account
     let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
@@ -391,7 +440,11 @@ fn test_sweep_to_unauthorized_destination() {
     let (authorized_signer, _) = generate_test_keypair();
     let authorized_dest = Address::generate(&env);
     let unauthorized_dest = Address::generate(&env);
-    controller_client.initialize(&authorized_signer, &Some(authorized_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(authorized_dest.clone()),
+        &Address::generate(&env),
+    );
 
     // Deploy ephemeral account
     let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
@@ -433,7 +486,11 @@ fn test_update_authorized_destination_by_creator() {
     let new_dest = Address::generate(&env);
 
     // Initialize with authorized destination
-    controller_client.initialize(&authorized_signer, &Some(initial_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(initial_dest.clone()),
+        &Address::generate(&env),
+    );
 
     // Update destination as creator (with mocked auth) - should succeed
     controller_client.update_authorized_destination(&new_dest);
@@ -455,7 +512,11 @@ fn test_update_authorized_destination_by_non_creator() {
 
     // Initialize with authorized destination
     // The invoker of initialize becomes the creator
-    controller_client.initialize(&authorized_signer, &Some(initial_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(initial_dest.clone()),
+        &Address::generate(&env),
+    );
 
     // Try to update destination - should fail because current invoker != creator
     // (In tests, the invoker is typically the contract itself or test framework)
@@ -481,7 +542,11 @@ fn test_update_destination_before_sweep() {
     let new_dest = Address::generate(&env);
 
     // Initialize with authorized destination
-    controller_client.initialize(&authorized_signer, &Some(initial_dest.clone()));
+    controller_client.initialize(
+        &authorized_signer,
+        &Some(initial_dest.clone()),
+        &Address::generate(&env),
+    );
 
     // Update destination before any sweep - should succeed
     controller_client.update_authorized_destination(&new_dest);
@@ -513,3 +578,534 @@ fn test_update_destination_before_sweep() {
     // For now, we just check it doesn't panic with UnauthorizedDestination
     // (In a real test, we'd check the panic message)
 }
+
+/// Test nonce consumption is reported after a sweep
+#[test]
+fn test_is_nonce_used() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (signing_key, authorized_signer) = generate_signing_key(&env);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
+
+    let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
+    let ephemeral_client = EphemeralAccountContractClient::new(&env, &ephemeral_id);
+
+    let creator = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let destination = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+
+    ephemeral_client.initialize(&creator, &expiry, &recovery);
+    ephemeral_client.record_payment(&100, &asset);
+
+    // Nothing consumed before the first sweep
+    assert!(!controller_client.is_nonce_used(&0));
+
+    let auth_sig = sign_sweep(&env, &signing_key, &controller_id, &destination, 0);
+    controller_client.execute_sweep(&ephemeral_id, &destination, &auth_sig);
+
+    assert!(controller_client.is_nonce_used(&0));
+    assert!(!controller_client.is_nonce_used(&1));
+    assert!(!controller_client.is_nonce_used(&2));
+}
+
+/// Test factory deployment at a deterministic address
+#[test]
+fn test_deploy_account_deterministic() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (_, authorized_signer) = generate_signing_key(&env);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(ephemeral_account_wasm::WASM);
+    controller_client.set_account_wasm_hash(&wasm_hash);
+
+    let creator = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+
+    // The address can be predicted from the controller, creator and salt
+    let mut preimage = creator.clone().to_xdr(&env);
+    preimage.append(&salt.clone().into());
+    let derived_salt: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let expected = env
+        .deployer()
+        .with_address(controller_id.clone(), derived_salt)
+        .deployed_address();
+    assert_eq!(controller_client.account_address(&creator, &salt), expected);
+
+    let account = controller_client.deploy_account(&salt, &creator, &expiry, &recovery);
+    assert_eq!(account, expected);
+
+    // The deployed account is initialized and consults this controller's denylist
+    let account_client = EphemeralAccountContractClient::new(&env, &account);
+    assert_eq!(account_client.get_status(), AccountStatus::Active);
+    assert_eq!(account_client.get_info().creator, creator);
+    assert_eq!(
+        account_client.features().get(symbol_short!("denylist")),
+        Some(true)
+    );
+
+    // Redeploying with the same salt fails
+    let result = controller_client.try_deploy_account(&salt, &creator, &expiry, &recovery);
+    assert!(result.is_err());
+}
+
+/// Test another creator cannot take a creator's predicted address
+#[test]
+fn test_deploy_account_bound_to_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (_, authorized_signer) = generate_signing_key(&env);
+    controller_client.initialize(&authorized_signer, &None, &Address::generate(&env));
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(ephemeral_account_wasm::WASM);
+    controller_client.set_account_wasm_hash(&wasm_hash);
+
+    let victim = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+
+    let predicted = controller_client.account_address(&victim, &salt);
+
+    // Front-running with the victim's salt lands somewhere else
+    let attacker_account = controller_client.deploy_account(&salt, &attacker, &expiry, &recovery);
+    assert_ne!(attacker_account, predicted);
+
+    // The victim still gets the predicted address
+    let account = controller_client.deploy_account(&salt, &victim, &expiry, &recovery);
+    assert_eq!(account, predicted);
+    let account_client = EphemeralAccountContractClient::new(&env, &account);
+    assert_eq!(account_client.get_info().creator, victim);
+}
+
+/// Test only the admin can set the factory wasm hash
+#[test]
+fn test_set_account_wasm_hash_requires_admin() {
+    let env = Env::default();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (_, authorized_signer) = generate_signing_key(&env);
+    let admin = Address::generate(&env);
+    controller_client.initialize(&authorized_signer, &None, &admin);
+
+    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    // Another address cannot authorize the call
+    let other = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &other,
+        invoke: &MockAuthInvoke {
+            contract: &controller_id,
+            fn_name: "set_account_wasm_hash",
+            args: (&wasm_hash,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(controller_client
+        .try_set_account_wasm_hash(&wasm_hash)
+        .is_err());
+
+    // The admin can
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &controller_id,
+            fn_name: "set_account_wasm_hash",
+            args: (&wasm_hash,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    controller_client.set_account_wasm_hash(&wasm_hash);
+}
+
+/// Helper function to deploy an initialized ephemeral account with one payment per amount
+fn setup_funded_account(env: &Env, amounts: &[i128]) -> Address {
+    let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
+    let ephemeral_client = EphemeralAccountContractClient::new(env, &ephemeral_id);
+
+    let creator = Address::generate(env);
+    let recovery = Address::generate(env);
+    let expiry = env.ledger().sequence() + 1000;
+
+    ephemeral_client.initialize(&creator, &expiry, &recovery);
+    for amount in amounts {
+        ephemeral_client.record_payment(amount, &Address::generate(env));
+    }
+
+    ephemeral_id
+}
+
+/// Test accounts are ordered by total value, highest first
+#[test]
+fn test_prioritized_accounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let low = setup_funded_account(&env, &[100]);
+    let high = setup_funded_account(&env, &[100, 200]);
+    let mid = setup_funded_account(&env, &[200]);
+
+    let accounts = Vec::from_array(&env, [low.clone(), high.clone(), mid.clone()]);
+    let ordered = controller_client.prioritized_accounts(&accounts);
+
+    assert_eq!(
+        ordered,
+        Vec::from_array(&env, [high.clone(), mid.clone(), low])
+    );
+
+    // Equal totals are ordered by address
+    let tied = setup_funded_account(&env, &[150, 50]);
+    let accounts = Vec::from_array(&env, [tied.clone(), mid.clone()]);
+    let ordered = controller_client.prioritized_accounts(&accounts);
+
+    let expected = if mid < tied { [mid, tied] } else { [tied, mid] };
+    assert_eq!(ordered, Vec::from_array(&env, expected));
+
+    // Unreadable accounts rank below even an empty one
+    let empty = setup_funded_account(&env, &[]);
+    let uninitialized = env.register_contract(None, EphemeralAccountContract);
+    let accounts = Vec::from_array(&env, [uninitialized.clone(), empty.clone(), high.clone()]);
+    let ordered = controller_client.prioritized_accounts(&accounts);
+
+    assert_eq!(ordered, Vec::from_array(&env, [high, empty, uninitialized]));
+}
+
+/// Test managed accounts reject assets on the controller's denylist
+#[test]
+fn test_denylist_enforced_on_recording() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (_, authorized_signer) = generate_signing_key(&env);
+    let admin = Address::generate(&env);
+    controller_client.initialize(&authorized_signer, &None, &admin);
+
+    let denied_asset = Address::generate(&env);
+    let allowed_asset = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &controller_id,
+            fn_name: "set_asset_denied",
+            args: (&denied_asset, true).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    controller_client.set_asset_denied(&denied_asset, &true);
+    env.mock_all_auths();
+    assert!(controller_client.is_denied(&denied_asset));
+    assert!(!controller_client.is_denied(&allowed_asset));
+
+    let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
+    let ephemeral_client = EphemeralAccountContractClient::new(&env, &ephemeral_id);
+
+    let creator = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+
+    ephemeral_client.initialize(&creator, &expiry, &recovery);
+    ephemeral_client.set_denylist_source(&controller_id);
+
+    let result = ephemeral_client.try_record_payment(&100, &denied_asset);
+    assert_eq!(result, Err(Ok(AccountError::AssetNotAllowed)));
+
+    ephemeral_client.record_payment(&100, &allowed_asset);
+    assert_eq!(ephemeral_client.get_info().payment_count, 1);
+
+    // Removing the asset from the denylist allows it again
+    controller_client.set_asset_denied(&denied_asset, &false);
+    ephemeral_client.record_payment(&100, &denied_asset);
+    assert_eq!(ephemeral_client.get_info().payment_count, 2);
+
+    // The source cannot be swapped once payments have been recorded
+    let result = ephemeral_client.try_set_denylist_source(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(AccountError::InvalidStatus)));
+}
+
+/// Test sweepable value is summed per asset over sweepable accounts only
+#[test]
+fn test_total_sweepable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let usdc = Address::generate(&env);
+    let eurc = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+
+    let mut accounts = Vec::new(&env);
+    let mut clients = std::vec::Vec::new();
+    for _ in 0..3 {
+        let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
+        let ephemeral_client = EphemeralAccountContractClient::new(&env, &ephemeral_id);
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        ephemeral_client.initialize(&creator, &expiry, &recovery);
+        accounts.push_back(ephemeral_id);
+        clients.push(ephemeral_client);
+    }
+
+    // Two sweepable accounts sharing an asset
+    clients[0].record_payment(&100, &usdc);
+    clients[0].record_payment(&50, &eurc);
+    clients[1].record_payment(&200, &usdc);
+
+    // An already swept account is not counted
+    clients[2].record_payment(&1000, &usdc);
+    let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+    clients[2].sweep(&Address::generate(&env), &auth_sig);
+
+    // Below the account's minimum asset count
+    let bundle_id = env.register_contract(None, EphemeralAccountContract);
+    let bundle_client = EphemeralAccountContractClient::new(&env, &bundle_id);
+    bundle_client.initialize(&Address::generate(&env), &expiry, &Address::generate(&env));
+    bundle_client.set_min_assets_to_sweep(&2);
+    bundle_client.record_payment(&400, &usdc);
+    accounts.push_back(bundle_id);
+
+    // Uninitialized addresses are skipped rather than failing the aggregate
+    accounts.push_back(env.register_contract(None, EphemeralAccountContract));
+
+    let totals = controller_client.total_sweepable(&accounts);
+
+    let mut expected = Map::new(&env);
+    expected.set(usdc, 300);
+    expected.set(eurc, 50);
+    assert_eq!(totals, expected);
+}
+
+/// Test the authorization configuration reflects a signature-auth setup
+#[test]
+fn test_auth_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    // Not initialized yet
+    assert_eq!(
+        controller_client.try_auth_config(),
+        Err(Ok(Error::AuthorizedSignerNotSet))
+    );
+
+    let (signing_key, authorized_signer) = generate_signing_key(&env);
+    let destination = Address::generate(&env);
+    let admin = Address::generate(&env);
+    controller_client.initialize(&authorized_signer, &Some(destination.clone()), &admin);
+
+    assert_eq!(
+        controller_client.auth_config(),
+        AuthConfig {
+            creator: controller_id.clone(),
+            admin: Some(admin.clone()),
+            authorized_signer: authorized_signer.clone(),
+            authorized_destination: Some(destination.clone()),
+            sweep_nonce: 0,
+        }
+    );
+
+    // The reported nonce moves with each sweep
+    let ephemeral_id = setup_funded_account(&env, &[100]);
+    let auth_sig = sign_sweep(&env, &signing_key, &controller_id, &destination, 0);
+    controller_client.execute_sweep(&ephemeral_id, &destination, &auth_sig);
+
+    assert_eq!(controller_client.auth_config().sweep_nonce, 1);
+}
+
+/// Test accounts are counted by status
+#[test]
+fn test_status_histogram() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let active = setup_funded_account(&env, &[]);
+    let paid_a = setup_funded_account(&env, &[100]);
+    let paid_b = setup_funded_account(&env, &[200, 300]);
+    let swept = setup_funded_account(&env, &[50]);
+
+    let auth_sig = BytesN::from_array(&env, &[0u8; 64]);
+    EphemeralAccountContractClient::new(&env, &swept).sweep(&Address::generate(&env), &auth_sig);
+
+    // Neither an uninitialized account nor a non-contract address is counted
+    let uninitialized = env.register_contract(None, EphemeralAccountContract);
+    let not_a_contract = Address::generate(&env);
+
+    let accounts = Vec::from_array(
+        &env,
+        [active, paid_a, uninitialized, paid_b, not_a_contract, swept],
+    );
+    let histogram = controller_client.status_histogram(&accounts);
+
+    let mut expected = Map::new(&env);
+    expected.set(symbol_short!("active"), 1);
+    expected.set(symbol_short!("paid"), 2);
+    expected.set(symbol_short!("swept"), 1);
+    assert_eq!(histogram, expected);
+}
+
+/// Test accounts are grouped by creator
+#[test]
+fn test_same_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let creator = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let expiry = env.ledger().sequence() + 1000;
+
+    let mut accounts = std::vec::Vec::new();
+    for account_creator in [&creator, &creator, &Address::generate(&env)] {
+        let ephemeral_id = env.register_contract(None, EphemeralAccountContract);
+        EphemeralAccountContractClient::new(&env, &ephemeral_id).initialize(
+            account_creator,
+            &expiry,
+            &recovery,
+        );
+        accounts.push(ephemeral_id);
+    }
+
+    assert!(controller_client.same_creator(&accounts[0], &accounts[1]));
+    assert!(!controller_client.same_creator(&accounts[0], &accounts[2]));
+
+    // An uninitialized account is rejected
+    let uninitialized = env.register_contract(None, EphemeralAccountContract);
+    assert_eq!(
+        controller_client.try_same_creator(&accounts[0], &uninitialized),
+        Err(Ok(Error::InvalidAccount))
+    );
+}
+
+/// Helper function to reserve a nonce with auth scoped to the admin
+fn reserve_nonce_as(
+    env: &Env,
+    controller_client: &SweepControllerClient,
+    admin: &Address,
+    account: &Address,
+) -> Result<u64, Error> {
+    env.mock_auths(&[MockAuth {
+        address: admin,
+        invoke: &MockAuthInvoke {
+            contract: &controller_client.address,
+            fn_name: "reserve_nonce",
+            args: (account,).into_val(env),
+            sub_invokes: &[],
+        },
+    }]);
+    match controller_client.try_reserve_nonce(account) {
+        Ok(Ok(nonce)) => Ok(nonce),
+        Err(Ok(err)) => Err(err),
+        _ => Err(Error::AuthorizationFailed),
+    }
+}
+
+/// Test a reserved nonce is used by the reserving account's sweep
+#[test]
+fn test_reserve_nonce_and_sweep() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (signing_key, authorized_signer) = generate_signing_key(&env);
+    let admin = Address::generate(&env);
+    controller_client.initialize(&authorized_signer, &None, &admin);
+
+    let reserved = setup_funded_account(&env, &[100]);
+    let other = setup_funded_account(&env, &[200]);
+    let destination = Address::generate(&env);
+
+    // Only the admin can reserve
+    let intruder = Address::generate(&env);
+    assert!(reserve_nonce_as(&env, &controller_client, &intruder, &reserved).is_err());
+
+    let nonce = reserve_nonce_as(&env, &controller_client, &admin, &reserved).unwrap();
+    assert_eq!(nonce, 0);
+
+    // Other accounts cannot reserve or sweep while the reservation is held
+    assert_eq!(
+        reserve_nonce_as(&env, &controller_client, &admin, &other),
+        Err(Error::NonceReserved)
+    );
+    let auth_sig = sign_sweep(&env, &signing_key, &controller_id, &destination, nonce);
+    assert_eq!(
+        controller_client.try_execute_sweep(&other, &destination, &auth_sig),
+        Err(Ok(Error::NonceReserved))
+    );
+
+    // The reserving account sweeps with the reserved nonce, releasing it
+    controller_client.execute_sweep(&reserved, &destination, &auth_sig);
+    assert!(controller_client.is_nonce_used(&nonce));
+    assert_eq!(
+        reserve_nonce_as(&env, &controller_client, &admin, &other),
+        Ok(nonce + 1)
+    );
+}
+
+/// Test an abandoned reservation lapses
+#[test]
+fn test_nonce_reservation_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let controller_id = env.register_contract(None, SweepController);
+    let controller_client = SweepControllerClient::new(&env, &controller_id);
+
+    let (_, authorized_signer) = generate_signing_key(&env);
+    let admin = Address::generate(&env);
+    controller_client.initialize(&authorized_signer, &None, &admin);
+
+    let crashed = setup_funded_account(&env, &[100]);
+    let other = setup_funded_account(&env, &[200]);
+
+    let nonce = reserve_nonce_as(&env, &controller_client, &admin, &crashed).unwrap();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += NONCE_RESERVATION_LEDGERS;
+    });
+
+    // The nonce is free again and was never consumed
+    assert_eq!(
+        reserve_nonce_as(&env, &controller_client, &admin, &other),
+        Ok(nonce)
+    );
+    assert!(!controller_client.is_nonce_used(&nonce));
+}